@@ -0,0 +1,4 @@
+extern crate num;
+extern crate rand;
+
+pub mod num_ext;
@@ -1,9 +1,62 @@
-use num::bigint::{ToBigUint, RandBigInt, BigUint};
-use num::{Zero, One};
+use num::bigint::{ToBigUint, ToBigInt, RandBigInt, BigUint, BigInt};
+use num::{Zero, One, ToPrimitive, Signed};
 use num::integer::Integer;
-use rand::thread_rng;
-use std::sync::{Arc, mpsc};
+use rand::{thread_rng, Rng};
+use std::sync::{Arc, mpsc, Once};
 use std::thread;
+use std::mem;
+
+#[cfg(feature = "secret")]
+pub mod secret;
+
+/// Upper bound (exclusive) for the small-prime sieve used to cheaply weed out composite
+/// candidates before paying for Miller-Rabin.
+const SMALL_PRIME_LIMIT: u64 = 1 << 16;
+
+static SMALL_PRIMES_INIT: Once = Once::new();
+static mut SMALL_PRIMES: *const Vec<u64> = std::ptr::null::<Vec<u64>>();
+
+/// The primes below `SMALL_PRIME_LIMIT`, sieved once on first use and cached for the
+/// lifetime of the process.
+fn small_primes() -> &'static Vec<u64> {
+    unsafe {
+        SMALL_PRIMES_INIT.call_once(|| {
+            SMALL_PRIMES = Box::into_raw(Box::new(sieve_small_primes(SMALL_PRIME_LIMIT)));
+        });
+        &*SMALL_PRIMES
+    }
+}
+
+fn sieve_small_primes(limit: u64) -> Vec<u64> {
+    let mut is_composite = vec![false; limit as usize];
+    let mut primes = Vec::new();
+    for n in 2..limit {
+        if is_composite[n as usize] {
+            continue;
+        }
+        primes.push(n);
+        let mut multiple = n * n;
+        while multiple < limit {
+            is_composite[multiple as usize] = true;
+            multiple += n;
+        }
+    }
+    primes
+}
+
+/// Does any small prime divide `n`? Returns `false` for the primes themselves.
+fn has_small_prime_factor(n: &BigUint) -> bool {
+    for &p in small_primes().iter() {
+        let p_big = p.to_biguint().unwrap();
+        if *n == p_big {
+            return false;
+        }
+        if n % &p_big == Zero::zero() {
+            return true;
+        }
+    }
+    false
+}
 
 /// Cryptographically useful extensions to the provided BigUint functionality.
 pub trait BigUintCrypto {
@@ -12,14 +65,23 @@ pub trait BigUintCrypto {
 
     fn next_prime_threaded(&self) -> BigUint;
     /// use the extended euclidean algorithm to solve for (g,x,y) given (a,b) such that
-    /// g = gcd(a,b) = a*x + b*y.
-    fn gcdext(&self, other: &BigUint) -> (BigUint, BigUint, BigUint);
+    /// g = gcd(a,b) = a*x + b*y. x and y are signed, so they are returned as BigInt.
+    fn gcdext(&self, other: &BigUint) -> (BigUint, BigInt, BigInt);
 
     /// Is this number a prime number. Uses a probablistic function to determine primality.
     fn is_prime(n: &BigUint) -> bool;
 
     /// perform the function (base^exponent) % modulus using exponentiation by sqauring
     fn mod_exp(base: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint;
+
+    /// Compute the modular inverse of `a` mod `m`, i.e. the `x` such that `a*x = 1 (mod m)`.
+    /// Returns `None` when `a` and `m` are not coprime, since no inverse exists in that case.
+    fn mod_inverse(a: &BigUint, m: &BigUint) -> Option<BigUint>;
+
+    /// Baillie-PSW primality test: a base-2 strong Miller-Rabin round followed by a strong
+    /// Lucas probable-prime test. No composite is known to pass both, so this is a faster,
+    /// equally trustworthy alternative to the 100-round random-base Miller-Rabin above.
+    fn is_prime_bpsw(n: &BigUint) -> bool;
 }
 
 impl BigUintCrypto for BigUint {
@@ -31,9 +93,31 @@ impl BigUintCrypto for BigUint {
         next_prime_helper(&self.clone(), true)
     }
 
-    fn gcdext(&self, other: &BigUint) -> (BigUint, BigUint, BigUint) {
+    fn gcdext(&self, other: &BigUint) -> (BigUint, BigInt, BigInt) {
+        let mut old_r = self.to_bigint().unwrap();
+        let mut r = other.to_bigint().unwrap();
+        let mut old_s: BigInt = One::one();
+        let mut s: BigInt = Zero::zero();
+        let mut old_t: BigInt = Zero::zero();
+        let mut t: BigInt = One::one();
+
+        while !r.is_zero() {
+            let q = &old_r / &r;
+
+            let new_r = &old_r - &q * &r;
+            old_r = r;
+            r = new_r;
+
+            let new_s = &old_s - &q * &s;
+            old_s = s;
+            s = new_s;
+
+            let new_t = &old_t - &q * &t;
+            old_t = t;
+            t = new_t;
+        }
 
-        (Zero::zero(), Zero::zero(), Zero::zero())
+        (old_r.to_biguint().unwrap(), old_s, old_t)
     }
 
     fn is_prime(n: &BigUint) -> bool {
@@ -51,11 +135,26 @@ impl BigUintCrypto for BigUint {
             if (&exp_acc % &two) == one {
                 result = (result * &base_acc) % modulus;
             }
-            exp_acc = exp_acc >> 1;
+            exp_acc >>= 1;
             base_acc = (&base_acc * &base_acc) % modulus;
         }
         result
     }
+
+    fn mod_inverse(a: &BigUint, m: &BigUint) -> Option<BigUint> {
+        let (g, old_s, _) = a.gcdext(m);
+        if g != One::one() {
+            return None;
+        }
+
+        let m_int = m.to_bigint().unwrap();
+        let inverse = ((old_s % &m_int) + &m_int) % &m_int;
+        inverse.to_biguint()
+    }
+
+    fn is_prime_bpsw(n: &BigUint) -> bool {
+        is_prime_bpsw_helper(n)
+    }
 }
 
 fn next_prime_helper(n: &BigUint, thread: bool) -> BigUint {
@@ -67,33 +166,84 @@ fn next_prime_helper(n: &BigUint, thread: bool) -> BigUint {
     } else {
         next_prime = &next_prime + &two;
     }
-    while !is_prime_helper(&next_prime, thread) {
+
+    // Track the candidate's residue mod each small prime so that stepping by 2 only
+    // costs a subtraction per prime instead of a fresh BigUint division.
+    let primes = small_primes();
+    let mut residues: Vec<u64> = primes.iter()
+        .map(|&p| (&next_prime % &p.to_biguint().unwrap()).to_u64().unwrap())
+        .collect();
+
+    loop {
+        let has_small_factor = primes.iter().zip(residues.iter())
+            .any(|(&p, &r)| r == 0 && next_prime != p.to_biguint().unwrap());
+
+        // the residue wheel above already proves next_prime has no small prime factor,
+        // so go straight to Miller-Rabin instead of paying for has_small_prime_factor's
+        // trial division a second time
+        if !has_small_factor && is_prime_miller_rabin_only(&next_prime, thread) {
+            break;
+        }
+
         next_prime = &next_prime + &two;
+        for (residue, &p) in residues.iter_mut().zip(primes.iter()) {
+            *residue = (*residue + 2) % p;
+        }
     }
     next_prime
 }
 
 fn is_prime_helper(n: &BigUint, thread: bool) -> bool {
+    if !is_prime_small_cases(n) {
+        return false;
+    }
+    if *n < 5.to_biguint().unwrap() {
+        return true;
+    }
+    if has_small_prime_factor(n) {
+        return false;
+    }
+    miller_rabin(n, 100, thread)
+}
+
+/// Same as `is_prime_helper`, but without the `has_small_prime_factor` trial division,
+/// for callers (such as `next_prime_helper`'s residue wheel) that have already ruled
+/// out small prime factors some other way.
+fn is_prime_miller_rabin_only(n: &BigUint, thread: bool) -> bool {
+    if !is_prime_small_cases(n) {
+        return false;
+    }
+    if *n < 5.to_biguint().unwrap() {
+        return true;
+    }
+    miller_rabin(n, 100, thread)
+}
+
+/// Handles the small/degenerate inputs (`n < 2`, `n` even, `n` one of the first two
+/// primes) shared by `is_prime_helper` and `is_prime_miller_rabin_only`. `false` means
+/// `n` is decided composite; `true` means either `n` is 2 or 3 (so prime), or `n` is an
+/// odd number >= 5 that the caller still needs to run further tests on.
+fn is_prime_small_cases(n: &BigUint) -> bool {
     let two = 2.to_biguint().unwrap();
     let three = 3.to_biguint().unwrap();
     if *n == three || *n == two {
         return true;
     }
-    if *n < two || n % two == Zero::zero() {
+    if *n < two || n % &two == Zero::zero() {
         return false;
     }
-    miller_rabin(n, 100, thread)
+    true
 }
 /// n must be greater than 3 and k indicates the number of rounds
 fn miller_rabin(n: &BigUint, k: usize, thread: bool) -> bool{
     let one: BigUint = One::one();
     let (tx, rx) = mpsc::channel();
 
-    let mut d: BigUint = n - &One::one();
+    let mut d: BigUint = n - &one;
     let mut s: BigUint = Zero::zero();
     while d.is_even() {
-        d = d >> 1;
-        s = s + &one;
+        d >>= 1;
+        s += &one;
     }
     if thread {
         let shared_n = Arc::new(n.clone());
@@ -110,12 +260,12 @@ fn miller_rabin(n: &BigUint, k: usize, thread: bool) -> bool{
             let shared_n = shared_n.clone();
             thread::spawn(move || {
                 let result = miller_rabin_thread(&shared_n, &shared_d, &shared_s, k/8);
-                tx.send(result);
+                let _ = tx.send(result);
                 });
         }
 
         for _ in 0..8 {
-            if !rx.recv().ok().expect("A thread failed") {
+            if !rx.recv().expect("A thread failed") {
                 return false;
             }
         }
@@ -148,7 +298,7 @@ fn miller_rabin_thread(n: &BigUint, d: &BigUint, s: &BigUint, k: usize) -> bool
             if x == (n - &one) {
                 break;
             }
-            i = i + &one;
+            i += &one;
         }
     }
     true
@@ -165,19 +315,321 @@ fn mod_exp(base: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
         if (&exp_acc % &two) == one {
             result = (result * &base_acc) % modulus;
         }
-        exp_acc = exp_acc >> 1;
+        exp_acc >>= 1;
         base_acc = (&base_acc * &base_acc) % modulus;
     }
     result
 }
 
+fn is_prime_bpsw_helper(n: &BigUint) -> bool {
+    let two = 2.to_biguint().unwrap();
+    let three = 3.to_biguint().unwrap();
+    if *n == two || *n == three {
+        return true;
+    }
+    if *n < two || n.is_even() {
+        return false;
+    }
+    // the Lucas step below assumes n is not a perfect square
+    if is_perfect_square(n) {
+        return false;
+    }
+    if !miller_rabin_base(n, &two) {
+        return false;
+    }
+    strong_lucas_probable_prime(n)
+}
+
+/// Integer square root via Newton's method, used to rule out perfect squares (which are
+/// never prime but can otherwise slip past the Lucas step below).
+fn is_perfect_square(n: &BigUint) -> bool {
+    if n.is_zero() {
+        return true;
+    }
+    let one: BigUint = One::one();
+    let mut x = n.clone();
+    let mut y: BigUint = (&x + &one) >> 1;
+    while y < x {
+        x = y;
+        y = (&x + n / &x) >> 1;
+    }
+    &x * &x == *n
+}
+
+/// A single strong Miller-Rabin round with a fixed base, as opposed to `miller_rabin`'s
+/// random bases. Used as the first half of the Baillie-PSW test.
+fn miller_rabin_base(n: &BigUint, a: &BigUint) -> bool {
+    let one: BigUint = One::one();
+    let two: BigUint = &one + &one;
+
+    let mut d: BigUint = n - &one;
+    let mut s: BigUint = Zero::zero();
+    while d.is_even() {
+        d >>= 1;
+        s += &one;
+    }
+
+    let mut x = mod_exp(a, &d, n);
+    if x == one || x == (n - &one) {
+        return true;
+    }
+
+    let mut i: BigUint = Zero::zero();
+    while i < (&s - &one) {
+        x = mod_exp(&x, &two, n);
+        if x == (n - &one) {
+            return true;
+        }
+        if x == one {
+            return false;
+        }
+        i += &one;
+    }
+    false
+}
+
+/// The Jacobi symbol (a/n) for odd n > 0.
+fn jacobi_symbol(a: &BigInt, n: &BigInt) -> i32 {
+    let zero: BigInt = Zero::zero();
+    let one: BigInt = One::one();
+    let two = &one + &one;
+    let four = &two + &two;
+    let eight = 8.to_bigint().unwrap();
+
+    let mut a = a.mod_floor(n);
+    let mut n = n.clone();
+    let mut result = 1;
+
+    while a != zero {
+        while (&a % &two) == zero {
+            a = &a / &two;
+            let r = (&n % &eight).to_i64().unwrap();
+            if r == 3 || r == 5 {
+                result = -result;
+            }
+        }
+        mem::swap(&mut a, &mut n);
+        let a_mod4 = (&a % &four).to_i64().unwrap();
+        let n_mod4 = (&n % &four).to_i64().unwrap();
+        if a_mod4 == 3 && n_mod4 == 3 {
+            result = -result;
+        }
+        a = a.mod_floor(&n);
+    }
+
+    if n == one { result } else { 0 }
+}
+
+/// Selfridge's method for picking Lucas parameters `D`, `P`, `Q`: scan `D` over
+/// 5, -7, 9, -11, 13, ... until the Jacobi symbol `(D/n)` is -1.
+/// Returns `None` if a factor of `n` turns up along the way, which means `n` is composite.
+fn select_lucas_params(n: &BigUint) -> Option<(BigInt, BigInt)> {
+    let n_int = n.to_bigint().unwrap();
+    let one: BigInt = One::one();
+    let four = &one + &one + &one + &one;
+
+    let mut magnitude: i64 = 5;
+    let mut positive = true;
+    loop {
+        let d = if positive {
+            magnitude.to_bigint().unwrap()
+        } else {
+            -magnitude.to_bigint().unwrap()
+        };
+
+        let g = d.abs().to_biguint().unwrap().gcd(n);
+        if g > One::one() && g != *n {
+            return None;
+        }
+
+        let j = jacobi_symbol(&d, &n_int);
+        if j == -1 {
+            let q = (&one - &d) / &four;
+            return Some((d, q));
+        }
+
+        magnitude += 2;
+        positive = !positive;
+    }
+}
+
+/// Strong Lucas probable-prime test with Selfridge parameters (P = 1). Computes the
+/// Lucas sequences `U`, `V` mod `n` via the doubling recurrences, walking the bits of
+/// `d` where `n + 1 = d * 2^s` with `d` odd.
+fn strong_lucas_probable_prime(n: &BigUint) -> bool {
+    let (d_param, q) = match select_lucas_params(n) {
+        Some(params) => params,
+        None => return false,
+    };
+
+    let n_int = n.to_bigint().unwrap();
+    let zero: BigInt = Zero::zero();
+    let one: BigInt = One::one();
+    let two = &one + &one;
+    let one_uint: BigUint = One::one();
+
+    let mut d: BigUint = n + &one_uint;
+    let mut s: BigUint = Zero::zero();
+    while d.is_even() {
+        d >>= 1;
+        s += &one_uint;
+    }
+
+    let total_bits = d.bits();
+
+    // k = 1: U_1 = 1, V_1 = P = 1, Q^1 = q
+    let mut u = one.clone();
+    let mut v = one.clone();
+    let mut qk = q.clone();
+
+    for i in (0..total_bits - 1).rev() {
+        // double: U_2k = U_k * V_k, V_2k = V_k^2 - 2*Q^k
+        u = mod_n(&(&u * &v), &n_int);
+        v = mod_n(&(&v * &v - &two * &qk), &n_int);
+        qk = mod_n(&(&qk * &qk), &n_int);
+
+        if (&d >> i).is_odd() {
+            // increment by one: U_{k+1} = (U_k + V_k)/2, V_{k+1} = (D*U_k + V_k)/2
+            let new_u = half_mod_n(&(&u + &v), &n_int);
+            let new_v = half_mod_n(&(&d_param * &u + &v), &n_int);
+            u = new_u;
+            v = new_v;
+            qk = mod_n(&(&qk * &q), &n_int);
+        }
+    }
+
+    if u == zero {
+        return true;
+    }
+
+    let mut r: BigUint = Zero::zero();
+    while r < s {
+        if v == zero {
+            return true;
+        }
+        if r < &s - &one_uint {
+            v = mod_n(&(&v * &v - &two * &qk), &n_int);
+            qk = mod_n(&(&qk * &qk), &n_int);
+        }
+        r += &one_uint;
+    }
+
+    false
+}
+
+/// Reduce `x` into `[0, n)`.
+fn mod_n(x: &BigInt, n: &BigInt) -> BigInt {
+    let zero: BigInt = Zero::zero();
+    let r = x % n;
+    if r < zero { r + n } else { r }
+}
+
+/// Divide `x` by two modulo the odd `n`, where `x` is first reduced into `[0, n)`.
+fn half_mod_n(x: &BigInt, n: &BigInt) -> BigInt {
+    let x = mod_n(x, n);
+    if x.is_even() {
+        x >> 1
+    } else {
+        (x + n) >> 1
+    }
+}
+
+/// Generate a random prime number that is exactly `bits` bits wide, suitable for use as
+/// an RSA/DH key component. The top bit is forced so the result is always the full
+/// width requested, and the low bit is forced so only odd candidates are tried.
+pub fn gen_prime(bits: usize) -> BigUint {
+    gen_prime_seeded(bits, &mut thread_rng())
+}
+
+/// Same as `gen_prime`, but draws candidates from the given random number generator
+/// instead of the thread-local RNG, so prime generation can be made reproducible for
+/// benchmarks and tests.
+///
+/// When built with the `secret` feature, each rejected candidate is held in a
+/// `SecretBigUint` and wiped as soon as it's replaced, since a caller building e.g. an
+/// RSA factor with this function is generating key material, not a throwaway value.
+#[cfg(not(feature = "secret"))]
+pub fn gen_prime_seeded<R: Rng>(bits: usize, rng: &mut R) -> BigUint {
+    let one: BigUint = One::one();
+    let two = &one + &one;
+    let top_bit = &one << (bits - 1);
+
+    loop {
+        let mut candidate = rng.gen_biguint(bits) | &top_bit | &one;
+        loop {
+            if BigUint::is_prime(&candidate) {
+                return candidate;
+            }
+            candidate = &candidate + &two;
+            // stepping by 2 can walk past 2^bits; redraw instead of returning a
+            // candidate that's wider than the requested bit length
+            if candidate.bits() > bits {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "secret")]
+pub fn gen_prime_seeded<R: Rng>(bits: usize, rng: &mut R) -> BigUint {
+    let one: BigUint = One::one();
+    let two = &one + &one;
+    let top_bit = &one << (bits - 1);
+    let width = bits.div_ceil(8);
+
+    loop {
+        let mut candidate = secret::SecretBigUint::new(rng.gen_biguint(bits) | &top_bit | &one, width);
+        loop {
+            if BigUint::is_prime(&candidate.expose()) {
+                return candidate.expose();
+            }
+            let next = candidate.expose() + &two;
+            if next.bits() > bits {
+                break;
+            }
+            candidate = secret::SecretBigUint::new(next, width);
+        }
+    }
+}
+
+/// Generate a safe prime `p` of the given bit width, i.e. one where `(p - 1) / 2` is
+/// also prime. This Sophie Germain / safe prime pair is what Diffie-Hellman and other
+/// discrete-log parameter generation require; plain `next_prime` cannot guarantee it.
+pub fn gen_safe_prime(bits: usize) -> BigUint {
+    gen_safe_prime_seeded(bits, &mut thread_rng())
+}
+
+/// Same as `gen_safe_prime`, but draws candidates from the given random number
+/// generator instead of the thread-local RNG.
+///
+/// When built with the `secret` feature, the Sophie Germain factor `q` is generated
+/// by the `secret`-aware `gen_prime_seeded` above, so the candidates it rejects along
+/// the way are wiped. `q` itself and `p` are plain `BigUint`s and are not wiped.
+pub fn gen_safe_prime_seeded<R: Rng>(bits: usize, rng: &mut R) -> BigUint {
+    let one: BigUint = One::one();
+    let two = &one + &one;
+
+    loop {
+        let q = gen_prime_seeded(bits - 1, rng);
+        let p = &two * &q + &one;
+
+        // is_prime already trial-divides p against the small-prime table before
+        // falling back to Miller-Rabin, so there's no need to sieve it again here
+        if BigUint::is_prime(&p) {
+            return p;
+        }
+    }
+}
+
 #[cfg(test)]
-mod test_BigUint_crypto {
-    use super::{BigUintCrypto, mod_exp};
-    use num::bigint::{RandBigInt, BigUint};
-    use std::num::FromPrimitive;
+mod test_big_uint_crypto {
+    use super::{BigUintCrypto, mod_exp, gen_prime_seeded, gen_safe_prime_seeded,
+                has_small_prime_factor, jacobi_symbol};
+    use num::bigint::{BigUint, BigInt, ToBigInt};
+    use num::FromPrimitive;
     use num::One;
-    use rand::thread_rng;
+    use rand::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn next_prime_test() {
@@ -220,7 +672,98 @@ mod test_BigUint_crypto {
     }
 
     #[test]
-    #[should_fail]
+    fn gcdext_test() {
+        let a = BigUint::from_isize(240).unwrap();
+        let b = BigUint::from_isize(46).unwrap();
+
+        let (g, x, y) = a.gcdext(&b);
+
+        assert!(g == BigUint::from_isize(2).unwrap());
+        assert!(x.to_bigint().unwrap() * a.to_bigint().unwrap() +
+                y.to_bigint().unwrap() * b.to_bigint().unwrap() == g.to_bigint().unwrap());
+    }
+
+    #[test]
+    fn mod_inverse_test() {
+        let a = BigUint::from_isize(17).unwrap();
+        let m = BigUint::from_isize(3120).unwrap();
+        let expected = BigUint::from_isize(2753).unwrap();
+
+        assert!(BigUint::mod_inverse(&a, &m) == Some(expected));
+    }
+
+    #[test]
+    fn mod_inverse_none_test() {
+        let a = BigUint::from_isize(4).unwrap();
+        let m = BigUint::from_isize(8).unwrap();
+
+        assert!(BigUint::mod_inverse(&a, &m).is_none());
+    }
+
+    #[test]
+    fn jacobi_symbol_test() {
+        let a = BigInt::from_isize(1001).unwrap();
+        let n = BigInt::from_isize(9907).unwrap();
+
+        assert!(jacobi_symbol(&a, &n) == -1);
+    }
+
+    #[test]
+    fn is_prime_bpsw_test() {
+        let known_prime = BigUint::
+        parse_bytes("4829837983753984028472098472089547098728675098723407520875297".as_bytes(), 10).unwrap();
+
+        assert!(BigUint::is_prime_bpsw(&known_prime));
+    }
+
+    #[test]
+    fn is_prime_bpsw_composite_test() {
+        let composite = BigUint::from_isize(341).unwrap(); // smallest base-2 Fermat pseudoprime
+
+        assert!(!BigUint::is_prime_bpsw(&composite));
+    }
+
+    #[test]
+    fn has_small_prime_factor_test() {
+        let composite = BigUint::from_isize(91).unwrap(); // 7 * 13
+        let prime = BigUint::from_isize(104729).unwrap();
+
+        assert!(has_small_prime_factor(&composite));
+        assert!(!has_small_prime_factor(&prime));
+    }
+
+    #[test]
+    fn gen_prime_seeded_test() {
+        let mut rng: StdRng = SeedableRng::from_seed(&[42usize][..]);
+        let prime = gen_prime_seeded(256, &mut rng);
+
+        assert!(BigUint::is_prime(&prime));
+        assert!(prime.bits() == 256);
+    }
+
+    #[test]
+    fn gen_prime_seeded_never_overflows_width_test() {
+        // a small bit width makes the "stepping by 2 walks past 2^bits" case common,
+        // so this is likely to exercise the redraw path across a handful of seeds
+        for seed in 0..32usize {
+            let mut rng: StdRng = SeedableRng::from_seed(&[seed][..]);
+            let prime = gen_prime_seeded(8, &mut rng);
+            assert!(prime.bits() == 8);
+        }
+    }
+
+    #[test]
+    fn gen_safe_prime_seeded_test() {
+        let mut rng: StdRng = SeedableRng::from_seed(&[7usize][..]);
+        let p = gen_safe_prime_seeded(64, &mut rng);
+        let q = (&p - BigUint::one()) / BigUint::from_isize(2).unwrap();
+
+        assert!(BigUint::is_prime(&p));
+        assert!(BigUint::is_prime(&q));
+    }
+
+    #[test]
+    #[should_panic]
     fn is_prime_test_failuire() {
         let not_prime = BigUint::
         parse_bytes("359709793871987301975987296195681798740165298740176567105918720469720137416098423"
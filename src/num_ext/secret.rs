@@ -0,0 +1,170 @@
+//! Opt-in helpers for handling secret material (private primes, private exponents)
+//! produced elsewhere in this module. Plain `BigUint`s are copied and reallocated
+//! freely by normal arithmetic and are left behind in memory once dropped; the types
+//! here are stored at a fixed byte width (so their size doesn't betray the secret's
+//! magnitude) and are zeroed on drop. Gated behind the `secret` feature since the
+//! extra zeroing, padding, and branchless arithmetic cost more than the defaults are
+//! worth for callers who aren't handling key material.
+//!
+//! This is a best-effort mitigation, not a hard guarantee: it narrows the two
+//! concrete leaks this crate's plain `BigUint` arithmetic has (branching on exponent
+//! bits in `mod_exp`, and `BigUint`'s value-dependent byte length), but it cannot
+//! protect values the caller exposes via `expose()` to feed into ordinary `BigUint`
+//! arithmetic (e.g. the multiply/square in `ct_mod_exp` below, or primality testing),
+//! which remain only as safe as the `num` crate's own (not constant-time) arithmetic.
+
+use num::bigint::BigUint;
+use num::One;
+
+/// A `BigUint` held at a fixed byte width, zeroed on drop. Use this in place of a bare
+/// `BigUint` for private-key material such as RSA primes, private exponents, or DH
+/// secrets, so its storage doesn't linger after it goes out of scope.
+pub struct SecretBigUint {
+    width: usize,
+    bytes: Vec<u8>,
+}
+
+impl SecretBigUint {
+    /// Wrap `value` at a fixed width of `width` bytes. `value` must fit in `width`
+    /// bytes (callers size `width` from a public bound, e.g. the RSA modulus length).
+    pub fn new(value: BigUint, width: usize) -> SecretBigUint {
+        SecretBigUint { width, bytes: pad_be(&value.to_bytes_be(), width) }
+    }
+
+    /// Recover the underlying value. The caller is responsible for not letting the
+    /// result linger unprotected longer than necessary.
+    pub fn expose(&self) -> BigUint {
+        BigUint::from_bytes_be(&self.bytes)
+    }
+
+    /// The i-th bit (0 = least significant) of the wrapped value, without exposing the
+    /// whole value. Reads past the fixed width are reported as unset.
+    pub fn bit(&self, i: usize) -> bool {
+        let total_bits = self.width * 8;
+        if i >= total_bits {
+            return false;
+        }
+        let byte = self.bytes[self.width - 1 - (i / 8)];
+        (byte >> (i % 8)) & 1 == 1
+    }
+
+    /// Compare two same-width secrets without branching on the position of the first
+    /// differing byte, so equality checks don't leak timing information.
+    pub fn ct_eq(&self, other: &SecretBigUint) -> bool {
+        if self.width != other.width {
+            return false;
+        }
+
+        let mut diff = 0u8;
+        for (a, b) in self.bytes.iter().zip(other.bytes.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+}
+
+impl Drop for SecretBigUint {
+    fn drop(&mut self) {
+        for byte in self.bytes.iter_mut() {
+            *byte = 0;
+        }
+    }
+}
+
+/// Modular exponentiation for a secret exponent. Unlike `BigUintCrypto::mod_exp`, the
+/// loop always runs the exponent's fixed public width in bits (`exponent.width * 8`)
+/// rather than stopping once the exponent's own significant bits run out, and every
+/// iteration always computes the multiply and picks the real result with a
+/// branchless, bytewise select instead of an `if` on the exponent's bit. Bounding the
+/// loop by the exponent's width rather than the modulus's matters for correctness, not
+/// just timing: an exponent wider than the modulus would otherwise have its high bits
+/// silently skipped. This closes the two observable-by-timing decisions `mod_exp`
+/// makes based on the exponent; it does not make the surrounding `BigUint`
+/// multiply/square/rem constant-time, which is a `num`-crate limitation this wrapper
+/// can't reach.
+pub fn ct_mod_exp(base: &BigUint, exponent: &SecretBigUint, modulus: &BigUint) -> BigUint {
+    let one: BigUint = One::one();
+    let width = modulus.bits().div_ceil(8);
+
+    let mut result = one.clone();
+    let mut base_acc = base.clone();
+
+    for i in 0..(exponent.width * 8) {
+        let multiplied = (&result * &base_acc) % modulus;
+        result = ct_select(exponent.bit(i), &multiplied, &result, width);
+        base_acc = (&base_acc * &base_acc) % modulus;
+    }
+    result
+}
+
+/// Select `on_true` when `condition` holds, `on_false` otherwise, by masking every
+/// byte of both fixed-`width` big-endian representations rather than branching on
+/// `condition`. Both operands must fit in `width` bytes.
+fn ct_select(condition: bool, on_true: &BigUint, on_false: &BigUint, width: usize) -> BigUint {
+    let mask = 0u8.wrapping_sub(condition as u8);
+
+    let a = pad_be(&on_true.to_bytes_be(), width);
+    let b = pad_be(&on_false.to_bytes_be(), width);
+
+    let selected: Vec<u8> = a.iter().zip(b.iter())
+        .map(|(&x, &y)| (x & mask) | (y & !mask))
+        .collect();
+
+    BigUint::from_bytes_be(&selected)
+}
+
+fn pad_be(bytes: &[u8], width: usize) -> Vec<u8> {
+    let mut padded = vec![0u8; width - bytes.len()];
+    padded.extend_from_slice(bytes);
+    padded
+}
+
+#[cfg(test)]
+mod test_secret {
+    use super::{SecretBigUint, ct_mod_exp};
+    use num::bigint::BigUint;
+    use num::FromPrimitive;
+
+    #[test]
+    fn bit_test() {
+        let secret = SecretBigUint::new(BigUint::from_isize(0b1010).unwrap(), 4);
+
+        assert!(!secret.bit(0));
+        assert!(secret.bit(1));
+        assert!(!secret.bit(2));
+        assert!(secret.bit(3));
+        assert!(!secret.bit(31));
+    }
+
+    #[test]
+    fn ct_eq_test() {
+        let a = SecretBigUint::new(BigUint::from_isize(12345).unwrap(), 8);
+        let b = SecretBigUint::new(BigUint::from_isize(12345).unwrap(), 8);
+        let c = SecretBigUint::new(BigUint::from_isize(54321).unwrap(), 8);
+
+        assert!(a.ct_eq(&b));
+        assert!(!a.ct_eq(&c));
+    }
+
+    #[test]
+    fn ct_mod_exp_test() {
+        let base = BigUint::from_isize(4).unwrap();
+        let exponent = SecretBigUint::new(BigUint::from_isize(13).unwrap(), 8);
+        let modulus = BigUint::from_isize(497).unwrap();
+        let expected_result = BigUint::from_isize(445).unwrap();
+
+        assert!(ct_mod_exp(&base, &exponent, &modulus) == expected_result);
+    }
+
+    #[test]
+    fn ct_mod_exp_exponent_wider_than_modulus_test() {
+        // 2^8 mod 7 = 4; the exponent (8, needs 4 bits) is wider than the modulus (7,
+        // needs 3 bits), which used to get truncated by a loop bounded on modulus bits
+        let base = BigUint::from_isize(2).unwrap();
+        let exponent = SecretBigUint::new(BigUint::from_isize(8).unwrap(), 8);
+        let modulus = BigUint::from_isize(7).unwrap();
+        let expected_result = BigUint::from_isize(4).unwrap();
+
+        assert!(ct_mod_exp(&base, &exponent, &modulus) == expected_result);
+    }
+}